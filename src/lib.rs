@@ -1,8 +1,13 @@
 use reqwest::Response;
 use time::OffsetDateTime;
 
+#[cfg(feature = "middleware")]
+pub mod middleware;
+
 pub trait ResponseExt {
     fn deprecation(&self) -> Option<Deprecation>;
+    fn warnings(&self) -> Vec<Warning>;
+    fn sunset(&self) -> Option<Sunset>;
 }
 
 #[derive(Debug)]
@@ -13,57 +18,332 @@ pub struct Deprecation {
     /// A link pointing to information about the deprecated resource.
     ///
     /// If present, we extracted it from a `Link` header with the relation `deprecation`.
-    pub deprecation_link: Option<String>,
+    pub deprecation_link: Option<LinkTarget>,
+    /// When the resource is expected to become unavailable, per RFC 8594.
+    pub sunset: Option<OffsetDateTime>,
+    /// A link pointing to information about the sunset.
+    ///
+    /// If present, we extracted it from a `Link` header with the relation `sunset`.
+    pub sunset_link: Option<LinkTarget>,
+}
+
+/// The `Sunset` header (RFC 8594), advertising when a resource is expected to become
+/// unavailable.
+///
+/// Unlike [`Deprecation`], a resource can advertise a sunset without being deprecated yet,
+/// which is why this is also exposed standalone via [`ResponseExt::sunset`].
+#[derive(Debug)]
+pub struct Sunset {
+    /// The timestamp specified in the `Sunset` header.
+    pub timestamp: Option<OffsetDateTime>,
+    /// A link pointing to information about the sunset.
+    ///
+    /// If present, we extracted it from a `Link` header with the relation `sunset`.
+    pub link: Option<LinkTarget>,
+}
+
+/// The resolved target of a `Link` header relation.
+///
+/// `Link` targets are frequently relative, so we resolve them against the response's request
+/// URL (see [`reqwest::Response::url`]). Absolute targets are preserved unchanged. When there
+/// is no base to resolve against, or the value isn't a valid URL even once resolved, we fall
+/// back to the raw string so no information is lost.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinkTarget {
+    Resolved(url::Url),
+    Raw(String),
+}
+
+/// A single warning conveyed via the `Warning` header (RFC 7234, section 5.5).
+///
+/// Many APIs signal deprecation this way, using warn-code `299`, rather than via the
+/// dedicated `Deprecation` header. See [`Warning::is_deprecation`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warning {
+    pub code: u16,
+    pub agent: String,
+    pub text: String,
+    pub date: Option<OffsetDateTime>,
+}
+
+impl Warning {
+    /// Whether this is warn-code `299` ("Miscellaneous Persistent Warning"), the convention
+    /// many APIs use to signal deprecation without a dedicated `Deprecation` header.
+    pub fn is_deprecation(&self) -> bool {
+        self.code == 299
+    }
 }
 
 impl ResponseExt for Response {
     fn deprecation(&self) -> Option<Deprecation> {
-        let value = self.headers().get(HEADER_NAME)?;
-
-        let deprecation_link = self.headers().get_all("Link").iter().find_map(|link| {
-            let value_as_str = link.to_str().ok()?;
-            let link = parse_deprecation_link(value_as_str)?;
+        let value = self.headers().get(DEPRECATION_HEADER_NAME)?;
 
-            Some(link.to_owned())
-        });
+        let deprecation_link = find_link(self, "deprecation");
+        let (sunset, sunset_link) = parse_sunset(self);
 
         if value == "true" {
             return Some(Deprecation {
                 timestamp: None,
                 deprecation_link,
+                sunset,
+                sunset_link,
             });
         }
 
-        let timestamp = OffsetDateTime::parse(
-            value.to_str().ok()?,
-            &time::format_description::well_known::Rfc2822,
-        )
-        .ok();
+        let timestamp = parse_http_date(value.to_str().ok()?);
 
         Some(Deprecation {
             timestamp,
             deprecation_link,
+            sunset,
+            sunset_link,
         })
     }
+
+    fn warnings(&self) -> Vec<Warning> {
+        self.headers()
+            .get_all(WARNING_HEADER_NAME)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(split_warning_values)
+            .filter_map(|value| parse_warning_value(value.trim()))
+            .collect()
+    }
+
+    fn sunset(&self) -> Option<Sunset> {
+        self.headers().get(SUNSET_HEADER_NAME)?;
+
+        let (timestamp, link) = parse_sunset(self);
+
+        Some(Sunset { timestamp, link })
+    }
+}
+
+const DEPRECATION_HEADER_NAME: &str = "Deprecation";
+const WARNING_HEADER_NAME: &str = "Warning";
+const SUNSET_HEADER_NAME: &str = "Sunset";
+
+fn parse_sunset(response: &Response) -> (Option<OffsetDateTime>, Option<LinkTarget>) {
+    let timestamp = response
+        .headers()
+        .get(SUNSET_HEADER_NAME)
+        .and_then(|value| parse_http_date(value.to_str().ok()?));
+
+    let link = find_link(response, "sunset");
+
+    (timestamp, link)
+}
+
+fn find_link(response: &Response, rel: &str) -> Option<LinkTarget> {
+    let raw = response.headers().get_all("Link").iter().find_map(|link| {
+        let value_as_str = link.to_str().ok()?;
+        let link = parse_link_relation(value_as_str, rel)?;
+
+        Some(link.to_owned())
+    })?;
+
+    Some(resolve_link(response, raw))
+}
+
+/// Resolves a raw `Link` target against the response's request URL, preserving absolute
+/// targets unchanged and falling back to the raw string if resolution isn't possible.
+fn resolve_link(response: &Response, raw: String) -> LinkTarget {
+    if !is_valid_uri_reference(&raw) {
+        return LinkTarget::Raw(raw);
+    }
+
+    match response.url().join(&raw) {
+        Ok(url) => LinkTarget::Resolved(url),
+        Err(_) => LinkTarget::Raw(raw),
+    }
+}
+
+/// A minimal RFC 3986 sanity check for `raw`.
+///
+/// `Url::join` follows the WHATWG URL algorithm, which percent-encodes unencoded whitespace
+/// and control characters instead of rejecting them, so a genuinely malformed `Link` target
+/// would otherwise always resolve successfully and never fall back to [`LinkTarget::Raw`].
+fn is_valid_uri_reference(raw: &str) -> bool {
+    !raw.is_empty() && raw.chars().all(|c| !c.is_whitespace() && !c.is_control())
+}
+
+/// Parses an HTTP-date per RFC 7231 section 7.1.1.1, trying each of the three permitted
+/// formats in turn: IMF-fixdate, the obsolete RFC 850 format, and the obsolete asctime format.
+fn parse_http_date(input: &str) -> Option<OffsetDateTime> {
+    if let Ok(date) =
+        OffsetDateTime::parse(input, &time::format_description::well_known::Rfc2822)
+    {
+        return Some(date);
+    }
+
+    if let Some(date) = parse_rfc850_date(input) {
+        return Some(date);
+    }
+
+    parse_asctime_date(input)
+}
+
+/// Parses the obsolete RFC 850 date format, e.g. `Sunday, 06-Nov-94 08:49:37 GMT`.
+///
+/// The two-digit year is expanded using the 50-year rolling window from RFC 7231 section
+/// 7.1.1.1: `00..=68` maps to `2000..=2068`, `69..=99` maps to `1969..=1999`.
+fn parse_rfc850_date(input: &str) -> Option<OffsetDateTime> {
+    let (_weekday, rest) = input.split_once(", ")?;
+    let mut parts = rest.split_whitespace();
+
+    let mut date_parts = parts.next()?.split('-');
+    let day: u8 = date_parts.next()?.parse().ok()?;
+    let month = parse_month(date_parts.next()?)?;
+    let year = expand_two_digit_year(date_parts.next()?.parse().ok()?);
+
+    let time = parse_clock_time(parts.next()?)?;
+
+    let date = time::Date::from_calendar_date(year, month, day).ok()?;
+
+    Some(time::PrimitiveDateTime::new(date, time).assume_utc())
+}
+
+/// Parses the obsolete asctime date format, e.g. `Sun Nov  6 08:49:37 1994`. This format has
+/// no timezone and is interpreted as UTC.
+fn parse_asctime_date(input: &str) -> Option<OffsetDateTime> {
+    let mut parts = input.split_whitespace();
+
+    let _weekday = parts.next()?;
+    let month = parse_month(parts.next()?)?;
+    let day: u8 = parts.next()?.parse().ok()?;
+    let time = parse_clock_time(parts.next()?)?;
+    let year: i32 = parts.next()?.parse().ok()?;
+
+    let date = time::Date::from_calendar_date(year, month, day).ok()?;
+
+    Some(time::PrimitiveDateTime::new(date, time).assume_utc())
+}
+
+fn parse_clock_time(input: &str) -> Option<time::Time> {
+    let mut parts = input.split(':');
+
+    let hour: u8 = parts.next()?.parse().ok()?;
+    let minute: u8 = parts.next()?.parse().ok()?;
+    let second: u8 = parts.next()?.parse().ok()?;
+
+    time::Time::from_hms(hour, minute, second).ok()
+}
+
+fn parse_month(input: &str) -> Option<time::Month> {
+    Some(match input {
+        "Jan" => time::Month::January,
+        "Feb" => time::Month::February,
+        "Mar" => time::Month::March,
+        "Apr" => time::Month::April,
+        "May" => time::Month::May,
+        "Jun" => time::Month::June,
+        "Jul" => time::Month::July,
+        "Aug" => time::Month::August,
+        "Sep" => time::Month::September,
+        "Oct" => time::Month::October,
+        "Nov" => time::Month::November,
+        "Dec" => time::Month::December,
+        _ => return None,
+    })
 }
 
-const HEADER_NAME: &str = "Deprecation";
+fn expand_two_digit_year(year: u32) -> i32 {
+    if year <= 68 {
+        2000 + year as i32
+    } else {
+        1900 + year as i32
+    }
+}
 
-fn parse_deprecation_link(input: &str) -> Option<&str> {
+fn parse_link_relation<'a>(input: &'a str, rel: &str) -> Option<&'a str> {
     let mut parts = input.split(';').map(|p| p.trim());
 
     let url = parts.next()?.trim_start_matches('<').trim_end_matches('>');
+    let target = format!(r#""{rel}""#);
 
     loop {
         let param = parts.next()?;
         let [key, value]: [&str; 2] = param.split('=').collect::<Vec<_>>().try_into().ok()?;
 
-        if key == "rel" && value == r#""deprecation""# {
+        if key == "rel" && value == target {
             return Some(url);
         }
     }
 }
 
+/// Splits a `Warning` header value into its comma-separated `warning-value`s, respecting
+/// commas that appear inside the quoted `warn-text` or `warn-date`.
+fn split_warning_values(input: &str) -> Vec<&str> {
+    let mut values = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if in_quotes => {
+                // Consume the escaped character so a `\"` doesn't toggle `in_quotes`.
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                values.push(input[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    values.push(input[start..].trim());
+
+    values
+}
+
+/// Parses a single `warning-value`: `warn-code SP warn-agent SP warn-text [ SP warn-date ]`.
+fn parse_warning_value(input: &str) -> Option<Warning> {
+    let (code, rest) = input.split_once(' ')?;
+    let code = code.trim().parse().ok()?;
+
+    let (agent, rest) = rest.trim_start().split_once(' ')?;
+    let agent = agent.to_owned();
+
+    let (text, rest) = parse_quoted_string(rest.trim_start())?;
+
+    let date = rest
+        .trim()
+        .strip_prefix('"')
+        .and_then(|rest| parse_http_date(rest.strip_suffix('"')?));
+
+    Some(Warning {
+        code,
+        agent,
+        text,
+        date,
+    })
+}
+
+/// Parses a leading quoted-string off of `input`, returning the unescaped text and the
+/// remainder of `input` following the closing quote.
+fn parse_quoted_string(input: &str) -> Option<(String, &str)> {
+    let input = input.strip_prefix('"')?;
+
+    let mut text = String::new();
+    let mut chars = input.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some((_, escaped)) = chars.next() {
+                    text.push(escaped);
+                }
+            }
+            '"' => return Some((text, &input[i + 1..])),
+            _ => text.push(c),
+        }
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,7 +408,9 @@ mod tests {
         assert_eq!(deprecation.timestamp, None);
         assert_eq!(
             deprecation.deprecation_link,
-            Some("https://developer.example.com/deprecation".to_owned())
+            Some(LinkTarget::Resolved(
+                url::Url::parse("https://developer.example.com/deprecation").unwrap()
+            ))
         );
     }
 
@@ -148,7 +430,9 @@ mod tests {
         assert_eq!(deprecation.timestamp, None);
         assert_eq!(
             deprecation.deprecation_link,
-            Some("https://developer.example.com/deprecation".to_owned())
+            Some(LinkTarget::Resolved(
+                url::Url::parse("https://developer.example.com/deprecation").unwrap()
+            ))
         );
     }
 
@@ -157,7 +441,7 @@ mod tests {
         let link =
             r#"<https://developer.example.com/deprecation>; rel="deprecation"; type="text/html""#;
 
-        let link = parse_deprecation_link(link).unwrap();
+        let link = parse_link_relation(link, "deprecation").unwrap();
 
         assert_eq!(link, "https://developer.example.com/deprecation")
     }
@@ -167,8 +451,251 @@ mod tests {
         let link =
             r#"<https://developer.example.com/deprecation>;  type="text/html"; rel="deprecation";"#;
 
-        let link = parse_deprecation_link(link).unwrap();
+        let link = parse_link_relation(link, "deprecation").unwrap();
 
         assert_eq!(link, "https://developer.example.com/deprecation")
     }
+
+    #[test]
+    fn parse_warning_header() {
+        let response: reqwest::Response = http::Response::builder()
+            .header(
+                "Warning",
+                r#"299 api.example.com "Deprecated API: use newapi.example.com instead.""#,
+            )
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        let warnings = response.warnings();
+
+        assert_eq!(
+            warnings,
+            vec![Warning {
+                code: 299,
+                agent: "api.example.com".to_owned(),
+                text: "Deprecated API: use newapi.example.com instead.".to_owned(),
+                date: None,
+            }]
+        );
+        assert!(warnings[0].is_deprecation());
+    }
+
+    #[test]
+    fn parse_warning_header_with_date() {
+        let response: reqwest::Response = http::Response::builder()
+            .header(
+                "Warning",
+                r#"299 - "Deprecated" "Thu, 01 Jan 1970 00:00:00 +0000""#,
+            )
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        let warnings = response.warnings();
+
+        assert_eq!(warnings[0].date, Some(OffsetDateTime::UNIX_EPOCH));
+    }
+
+    #[test]
+    fn parse_warning_header_with_multiple_comma_separated_values() {
+        let response: reqwest::Response = http::Response::builder()
+            .header(
+                "Warning",
+                r#"110 anderson/1.3.37 "Response is stale", 299 - "Deprecated, use v2 instead""#,
+            )
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        let warnings = response.warnings();
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].code, 110);
+        assert_eq!(warnings[0].agent, "anderson/1.3.37");
+        assert_eq!(warnings[0].text, "Response is stale");
+        assert!(!warnings[0].is_deprecation());
+        assert_eq!(warnings[1].code, 299);
+        assert_eq!(warnings[1].text, "Deprecated, use v2 instead");
+        assert!(warnings[1].is_deprecation());
+    }
+
+    #[test]
+    fn parse_warning_header_with_escaped_quote_before_comma() {
+        let response: reqwest::Response = http::Response::builder()
+            .header(
+                "Warning",
+                r#"299 - "say \", then stop", 110 - "Response is stale""#,
+            )
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        let warnings = response.warnings();
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].text, r#"say ", then stop"#);
+        assert_eq!(warnings[1].code, 110);
+        assert_eq!(warnings[1].text, "Response is stale");
+    }
+
+    #[test]
+    fn no_warning_header_yields_no_warnings() {
+        let response: reqwest::Response = http::Response::builder()
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        assert_eq!(response.warnings(), vec![]);
+    }
+
+    #[test]
+    fn parse_deprecation_header_with_sunset() {
+        let response: reqwest::Response = http::Response::builder()
+            .header("Deprecation", "true")
+            .header("Sunset", "Thu, 01 Jan 1970 00:00:00 +0000")
+            .header(
+                "Link",
+                r#"<https://developer.example.com/sunset>; rel="sunset""#,
+            )
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        let deprecation = response.deprecation().unwrap();
+
+        assert_eq!(deprecation.sunset, Some(OffsetDateTime::UNIX_EPOCH));
+        assert_eq!(
+            deprecation.sunset_link,
+            Some(LinkTarget::Resolved(
+                url::Url::parse("https://developer.example.com/sunset").unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn resolves_relative_link_against_request_url() {
+        let response: reqwest::Response = http::Response::builder()
+            .header("Deprecation", "true")
+            .header("Link", r#"</docs/deprecation>; rel="deprecation""#)
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        let expected = response.url().join("/docs/deprecation").unwrap();
+
+        let deprecation = response.deprecation().unwrap();
+
+        assert_eq!(
+            deprecation.deprecation_link,
+            Some(LinkTarget::Resolved(expected))
+        );
+    }
+
+    #[test]
+    fn falls_back_to_raw_string_when_link_cannot_be_resolved() {
+        let response: reqwest::Response = http::Response::builder()
+            .header("Deprecation", "true")
+            .header("Link", r#"<not a url>; rel="deprecation""#)
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        let deprecation = response.deprecation().unwrap();
+
+        assert_eq!(
+            deprecation.deprecation_link,
+            Some(LinkTarget::Raw("not a url".to_owned()))
+        );
+    }
+
+    #[test]
+    fn parse_standalone_sunset_header() {
+        let response: reqwest::Response = http::Response::builder()
+            .header("Sunset", "Thu, 01 Jan 1970 00:00:00 +0000")
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        let sunset = response.sunset().unwrap();
+
+        assert_eq!(sunset.timestamp, Some(OffsetDateTime::UNIX_EPOCH));
+        assert_eq!(sunset.link, None);
+    }
+
+    #[test]
+    fn no_sunset_header_yields_no_sunset() {
+        let response: reqwest::Response = http::Response::builder()
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        assert!(response.sunset().is_none());
+    }
+
+    #[test]
+    fn parse_deprecation_header_with_rfc850_date() {
+        let response: reqwest::Response = http::Response::builder()
+            .header("Deprecation", "Thursday, 01-Jan-70 00:00:00 GMT")
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        let deprecation = response.deprecation().unwrap();
+
+        assert_eq!(deprecation.timestamp, Some(OffsetDateTime::UNIX_EPOCH));
+    }
+
+    #[test]
+    fn parse_deprecation_header_with_asctime_date() {
+        let response: reqwest::Response = http::Response::builder()
+            .header("Deprecation", "Thu Jan  1 00:00:00 1970")
+            .status(200)
+            .body(String::new())
+            .unwrap()
+            .into();
+
+        let deprecation = response.deprecation().unwrap();
+
+        assert_eq!(deprecation.timestamp, Some(OffsetDateTime::UNIX_EPOCH));
+    }
+
+    #[test]
+    fn rfc850_two_digit_year_expands_with_rolling_window() {
+        assert_eq!(
+            parse_rfc850_date("Sunday, 06-Nov-94 08:49:37 GMT")
+                .unwrap()
+                .year(),
+            1994
+        );
+        assert_eq!(
+            parse_rfc850_date("Thursday, 01-Jan-30 00:00:00 GMT")
+                .unwrap()
+                .year(),
+            2030
+        );
+        assert_eq!(
+            parse_rfc850_date("Thursday, 01-Jan-68 00:00:00 GMT")
+                .unwrap()
+                .year(),
+            2068
+        );
+        assert_eq!(
+            parse_rfc850_date("Thursday, 01-Jan-69 00:00:00 GMT")
+                .unwrap()
+                .year(),
+            1969
+        );
+    }
 }
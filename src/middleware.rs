@@ -0,0 +1,84 @@
+//! A [`reqwest-middleware`] layer that detects deprecation signals on every response, so
+//! individual call sites don't need to remember to invoke [`ResponseExt`](crate::ResponseExt).
+//!
+//! Requires the `middleware` feature.
+
+use async_trait::async_trait;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next, Result};
+use url::Url;
+
+use crate::{Deprecation, ResponseExt, Warning};
+
+/// Invoked with the request URL and the detected [`Deprecation`] whenever a response carries
+/// a `Deprecation`, `Sunset`, or `Warning: 299` signal.
+pub type DeprecationCallback = Box<dyn Fn(&Url, &Deprecation) + Send + Sync>;
+
+/// A [`reqwest-middleware`] layer that inspects every response for deprecation signals
+/// (`Deprecation`, `Sunset`, or `Warning: 299`) and reports them through a user-supplied
+/// callback.
+pub struct DeprecationMiddleware {
+    callback: DeprecationCallback,
+}
+
+impl DeprecationMiddleware {
+    /// Creates a middleware that invokes `callback` whenever a response signals deprecation.
+    pub fn new(callback: impl Fn(&Url, &Deprecation) + Send + Sync + 'static) -> Self {
+        Self {
+            callback: Box::new(callback),
+        }
+    }
+
+    /// Creates a middleware that logs deprecations via `tracing::warn!`, including the
+    /// resource URL, the parsed timestamp, and the documentation link (if any).
+    pub fn logging() -> Self {
+        Self::new(|url, deprecation| {
+            tracing::warn!(
+                %url,
+                timestamp = ?deprecation.timestamp,
+                link = ?deprecation.deprecation_link,
+                sunset = ?deprecation.sunset,
+                "deprecated API called",
+            );
+        })
+    }
+}
+
+#[async_trait]
+impl Middleware for DeprecationMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut http::Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        let url = req.url().clone();
+        let response = next.run(req, extensions).await?;
+
+        if let Some(deprecation) = response.deprecation() {
+            (self.callback)(&url, &deprecation);
+        } else if let Some(sunset) = response.sunset() {
+            (self.callback)(
+                &url,
+                &Deprecation {
+                    timestamp: None,
+                    deprecation_link: None,
+                    sunset: sunset.timestamp,
+                    sunset_link: sunset.link,
+                },
+            );
+        } else if response.warnings().iter().any(Warning::is_deprecation) {
+            (self.callback)(
+                &url,
+                &Deprecation {
+                    timestamp: None,
+                    deprecation_link: None,
+                    sunset: None,
+                    sunset_link: None,
+                },
+            );
+        }
+
+        Ok(response)
+    }
+}